@@ -29,3 +29,17 @@ impl Error for TaError {
         }
     }
 }
+
+/// A fallible counterpart to [`Next`](crate::Next) that rejects invalid
+/// input (e.g. `NaN`/infinite `f64` values) instead of silently feeding it
+/// into the indicator's internal state.
+///
+/// On `Err`, the indicator is left exactly as it was before the call, so
+/// callers can skip the bad tick and keep feeding subsequent data.
+pub trait TryNext<T> {
+    /// The result of the calculation, same as [`Next::Output`](crate::Next::Output).
+    type Output;
+
+    /// Feeds in the next input value, rejecting non-finite data.
+    fn try_next(&mut self, input: T) -> Result<Self::Output>;
+}
@@ -1,14 +1,25 @@
+#[cfg(feature = "alloc")]
 use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
 use alloc::vec;
 use core::fmt;
 
-use crate::errors::{Result, TaError};
+use crate::errors::{Result, TaError, TryNext};
 use crate::{High, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Returns the highest value in a given time frame.
 ///
+/// Internally this keeps a ring buffer of the last `period` raw values
+/// alongside a monotonic deque of indices into that buffer (kept strictly
+/// decreasing front-to-back), so `next` is O(1) amortized regardless of how
+/// the tracked maximum moves through the window.
+///
+/// The period is a runtime value backed by a heap allocation; on targets
+/// without a global allocator use [`MaximumN`] instead, which fixes the
+/// period at compile time and lives entirely on the stack.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -26,71 +37,92 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(max.next(4.0), 5.0);
 /// assert_eq!(max.next(8.0), 8.0);
 /// ```
+#[cfg(feature = "alloc")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct Maximum {
     period: usize,
-    max_index: usize,
-    cur_index: usize,
-    deque: Box<[f64]>,
+    values: Box<[f64]>,
+    // Monotonic deque of absolute value-indices, strictly decreasing by
+    // value front-to-back. Stored in its own fixed-size ring buffer so the
+    // struct stays allocation-stable after construction.
+    deque: Box<[usize]>,
+    deque_head: usize,
+    deque_len: usize,
+    count: usize,
 }
 
+#[cfg(feature = "alloc")]
 impl Maximum {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
-                max_index: 0,
-                cur_index: 0,
-                deque: vec![f64::NEG_INFINITY; period].into_boxed_slice(),
+                values: vec![f64::NEG_INFINITY; period].into_boxed_slice(),
+                deque: vec![0; period].into_boxed_slice(),
+                deque_head: 0,
+                deque_len: 0,
+                count: 0,
             }),
         }
     }
 
-    fn find_max_index(&self) -> usize {
-        let mut max = f64::NEG_INFINITY;
-        let mut index: usize = 0;
+    fn back(&self) -> usize {
+        self.deque[(self.deque_head + self.deque_len - 1) % self.period]
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val > max {
-                max = val;
-                index = i;
-            }
-        }
+    fn front(&self) -> usize {
+        self.deque[self.deque_head]
+    }
 
-        index
+    fn push_back(&mut self, index: usize) {
+        let slot = (self.deque_head + self.deque_len) % self.period;
+        self.deque[slot] = index;
+        self.deque_len += 1;
+    }
+
+    fn pop_back(&mut self) {
+        self.deque_len -= 1;
+    }
+
+    fn pop_front(&mut self) {
+        self.deque_head = (self.deque_head + 1) % self.period;
+        self.deque_len -= 1;
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Period for Maximum {
     fn period(&self) -> usize {
         self.period
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Next<f64> for Maximum {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.deque[self.cur_index] = input;
+        let index = self.count;
+        self.values[index % self.period] = input;
+
+        while self.deque_len > 0 && self.front() + self.period <= index {
+            self.pop_front();
+        }
 
-        if input > self.deque[self.max_index] {
-            self.max_index = self.cur_index;
-        } else if self.max_index == self.cur_index {
-            self.max_index = self.find_max_index();
+        while self.deque_len > 0 && self.values[self.back() % self.period] <= input {
+            self.pop_back();
         }
+        self.push_back(index);
 
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
-        };
+        self.count += 1;
 
-        self.deque[self.max_index]
+        self.values[self.front() % self.period]
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: High> Next<&T> for Maximum {
     type Output = f64;
 
@@ -99,27 +131,192 @@ impl<T: High> Next<&T> for Maximum {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl TryNext<f64> for Maximum {
+    type Output = f64;
+
+    fn try_next(&mut self, input: f64) -> Result<Self::Output> {
+        if !input.is_finite() {
+            return Err(TaError::DataItemInvalid);
+        }
+        Ok(self.next(input))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: High> TryNext<&T> for Maximum {
+    type Output = f64;
+
+    fn try_next(&mut self, input: &T) -> Result<Self::Output> {
+        self.try_next(input.high())
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl Reset for Maximum {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = f64::NEG_INFINITY;
-        }
+        self.deque_head = 0;
+        self.deque_len = 0;
+        self.count = 0;
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Default for Maximum {
     fn default() -> Self {
         Self::new(14).unwrap()
     }
 }
 
+#[cfg(feature = "alloc")]
 impl fmt::Display for Maximum {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "MAX({})", self.period)
     }
 }
 
-#[cfg(test)]
+/// Allocation-free, const-generic counterpart to [`Maximum`].
+///
+/// The period is fixed at compile time as `N`, so the indicator is backed
+/// by plain `[f64; N]`/`[usize; N]` arrays rather than a heap-allocated
+/// slice, and is available even when the `alloc` feature is disabled (e.g.
+/// on embedded targets with no global allocator).
+///
+/// # Example
+///
+/// ```
+/// use finlib_ta::indicators::MaximumN;
+/// use finlib_ta::Next;
+///
+/// let mut max = MaximumN::<3>::new();
+/// assert_eq!(max.next(7.0), 7.0);
+/// assert_eq!(max.next(5.0), 7.0);
+/// assert_eq!(max.next(4.0), 7.0);
+/// assert_eq!(max.next(4.0), 5.0);
+/// assert_eq!(max.next(8.0), 8.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MaximumN<const N: usize> {
+    values: [f64; N],
+    deque: [usize; N],
+    deque_head: usize,
+    deque_len: usize,
+    count: usize,
+}
+
+impl<const N: usize> MaximumN<N> {
+    pub fn new() -> Self {
+        assert!(N > 0, "period (N) must be greater than 0");
+        Self {
+            values: [f64::NEG_INFINITY; N],
+            deque: [0; N],
+            deque_head: 0,
+            deque_len: 0,
+            count: 0,
+        }
+    }
+
+    fn back(&self) -> usize {
+        self.deque[(self.deque_head + self.deque_len - 1) % N]
+    }
+
+    fn front(&self) -> usize {
+        self.deque[self.deque_head]
+    }
+
+    fn push_back(&mut self, index: usize) {
+        let slot = (self.deque_head + self.deque_len) % N;
+        self.deque[slot] = index;
+        self.deque_len += 1;
+    }
+
+    fn pop_back(&mut self) {
+        self.deque_len -= 1;
+    }
+
+    fn pop_front(&mut self) {
+        self.deque_head = (self.deque_head + 1) % N;
+        self.deque_len -= 1;
+    }
+}
+
+impl<const N: usize> Period for MaximumN<N> {
+    fn period(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Next<f64> for MaximumN<N> {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let index = self.count;
+        self.values[index % N] = input;
+
+        while self.deque_len > 0 && self.front() + N <= index {
+            self.pop_front();
+        }
+
+        while self.deque_len > 0 && self.values[self.back() % N] <= input {
+            self.pop_back();
+        }
+        self.push_back(index);
+
+        self.count += 1;
+
+        self.values[self.front() % N]
+    }
+}
+
+impl<const N: usize, T: High> Next<&T> for MaximumN<N> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.high())
+    }
+}
+
+impl<const N: usize> TryNext<f64> for MaximumN<N> {
+    type Output = f64;
+
+    fn try_next(&mut self, input: f64) -> Result<Self::Output> {
+        if !input.is_finite() {
+            return Err(TaError::DataItemInvalid);
+        }
+        Ok(self.next(input))
+    }
+}
+
+impl<const N: usize, T: High> TryNext<&T> for MaximumN<N> {
+    type Output = f64;
+
+    fn try_next(&mut self, input: &T) -> Result<Self::Output> {
+        self.try_next(input.high())
+    }
+}
+
+impl<const N: usize> Reset for MaximumN<N> {
+    fn reset(&mut self) {
+        self.deque_head = 0;
+        self.deque_len = 0;
+        self.count = 0;
+    }
+}
+
+impl<const N: usize> Default for MaximumN<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Display for MaximumN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MAX({})", N)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
     use crate::test_helper::*;
@@ -183,4 +380,95 @@ mod tests {
         let indicator = Maximum::new(7).unwrap();
         assert_eq!(format!("{}", indicator), "MAX(7)");
     }
+
+    #[test]
+    fn test_monotonic_decreasing_input() {
+        // A steady downtrend is the adversarial case for the old
+        // find_max_index scan: the tracked maximum keeps rolling out of the
+        // window on every tick, forcing a full O(period) rescan each time.
+        // The monotonic deque handles it in O(1) amortized instead.
+        let period = 100;
+        let mut max = Maximum::new(period).unwrap();
+
+        for i in 0..500 {
+            let input = 1_000.0 - i as f64;
+            let expected = if i < period {
+                1_000.0
+            } else {
+                1_000.0 - (i - period + 1) as f64
+            };
+            assert_eq!(max.next(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_try_next_rejects_non_finite() {
+        let mut max = Maximum::new(3).unwrap();
+
+        assert_eq!(max.try_next(4.0), Ok(4.0));
+        assert_eq!(max.try_next(f64::NAN), Err(TaError::DataItemInvalid));
+        assert_eq!(max.try_next(f64::INFINITY), Err(TaError::DataItemInvalid));
+        // State is untouched by the rejected inputs above.
+        assert_eq!(max.try_next(1.0), Ok(4.0));
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let mut max = MaximumN::<3>::new();
+
+        assert_eq!(max.next(4.0), 4.0);
+        assert_eq!(max.next(1.2), 4.0);
+        assert_eq!(max.next(5.0), 5.0);
+        assert_eq!(max.next(3.0), 5.0);
+        assert_eq!(max.next(4.0), 5.0);
+        assert_eq!(max.next(0.0), 4.0);
+        assert_eq!(max.next(-1.0), 4.0);
+        assert_eq!(max.next(-2.0), 0.0);
+        assert_eq!(max.next(-1.5), -1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut max = MaximumN::<100>::new();
+        assert_eq!(max.next(4.0), 4.0);
+        assert_eq!(max.next(10.0), 10.0);
+        assert_eq!(max.next(4.0), 10.0);
+
+        max.reset();
+        assert_eq!(max.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        MaximumN::<14>::default();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_period_panics() {
+        MaximumN::<0>::new();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display() {
+        let indicator = MaximumN::<7>::new();
+        assert_eq!(alloc::format!("{}", indicator), "MAX(7)");
+    }
+
+    #[test]
+    fn test_try_next_rejects_non_finite() {
+        let mut max = MaximumN::<3>::new();
+
+        assert_eq!(max.try_next(4.0), Ok(4.0));
+        assert_eq!(max.try_next(f64::NAN), Err(TaError::DataItemInvalid));
+        assert_eq!(max.try_next(f64::INFINITY), Err(TaError::DataItemInvalid));
+        // State is untouched by the rejected inputs above.
+        assert_eq!(max.try_next(1.0), Ok(4.0));
+    }
 }
@@ -0,0 +1,109 @@
+//! Lifts a synchronous [`Next`] indicator into an asynchronous
+//! [`futures::Stream`], so it can be driven directly from a live market
+//! data feed instead of being polled by hand.
+#![cfg(feature = "stream")]
+
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures::Stream;
+
+use crate::Next;
+
+/// Extension trait that lifts any [`Next`] indicator into an asynchronous
+/// [`Stream`] combinator.
+pub trait IndicatorExt<T>: Next<T> + Sized {
+    /// Wraps this indicator around `input`, returning a stream that yields
+    /// one output per item as it arrives, by calling [`Next::next`] on the
+    /// indicator. Ordering and back-pressure are inherited from `input`;
+    /// nothing beyond the indicator's own state is buffered.
+    ///
+    /// The returned [`IndicatorStream`] implements [`Stream`] only when both
+    /// the indicator and `input` are `Unpin`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use finlib_ta::indicators::MaximumN;
+    /// use finlib_ta::stream::IndicatorExt;
+    /// use futures::{executor::block_on, stream, StreamExt};
+    ///
+    /// let ticks = stream::iter([7.0, 5.0, 4.0, 4.0, 8.0]);
+    /// let outputs: Vec<f64> = block_on(MaximumN::<3>::new().into_stream(ticks).collect());
+    ///
+    /// assert_eq!(outputs, vec![7.0, 7.0, 7.0, 5.0, 8.0]);
+    /// ```
+    fn into_stream<S>(self, input: S) -> IndicatorStream<Self, S, T>
+    where
+        S: Stream<Item = T>,
+    {
+        IndicatorStream {
+            indicator: self,
+            input,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, I: Next<T>> IndicatorExt<T> for I {}
+
+/// Stream returned by [`IndicatorExt::into_stream`].
+pub struct IndicatorStream<I, S, T> {
+    indicator: I,
+    input: S,
+    _marker: PhantomData<T>,
+}
+
+impl<I, S, T> Stream for IndicatorStream<I, S, T>
+where
+    I: Next<T> + Unpin,
+    S: Stream<Item = T> + Unpin,
+{
+    type Item = I::Output;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.input).poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(self.indicator.next(item))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::MaximumN;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn test_into_stream_matches_manual_next() {
+        let inputs = [7.0, 5.0, 4.0, 4.0, 8.0];
+
+        let mut manual = MaximumN::<3>::new();
+        let expected: Vec<f64> = inputs.iter().map(|&v| manual.next(v)).collect();
+
+        let ticks = stream::iter(inputs);
+        let outputs: Vec<f64> = block_on(MaximumN::<3>::new().into_stream(ticks).collect());
+
+        assert_eq!(outputs, expected);
+    }
+
+    #[test]
+    fn test_into_stream_with_bars() {
+        use crate::indicators::MinimumN;
+        use crate::test_helper::Bar;
+
+        fn bar(low: f64) -> Bar {
+            Bar::new().low(low)
+        }
+
+        let bars = [bar(4.0), bar(4.0), bar(1.2), bar(5.0)];
+        let ticks = stream::iter(bars.iter());
+
+        let outputs: Vec<f64> = block_on(MinimumN::<3>::new().into_stream(ticks).collect());
+
+        assert_eq!(outputs, vec![4.0, 4.0, 1.2, 1.2]);
+    }
+}